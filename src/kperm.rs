@@ -0,0 +1,179 @@
+//! *k*-permutations: ordered arrangements of length `k` drawn from a
+//! slice of `n` elements (the `P(n, k) = n! / (n - k)!` partial
+//! permutations).
+
+/// Maximum length `k` supported by `KPermutations`, so that its per-depth
+/// bookkeeping can live in a fixed-size array instead of allocating.
+/// `k_permutations_recursive` has no such limit.
+pub const MAXKPERM: usize = 32;
+
+/// An iterator-like walker over the `P(n, k)` partial permutations of
+/// `data`, each returned as the first `k` elements of `data` itself
+/// (`Heap`-style, to avoid allocating a fresh `Vec` on every step).
+///
+/// Unvisited elements (from index `k` onward) are left in an unspecified
+/// order between steps; only the `k`-length prefix is meaningful.
+pub struct KPermutations<'a, T: 'a> {
+    data: &'a mut [T],
+    k: usize,
+    // c[depth] is the index (in `depth..data.len()`) currently swapped
+    // into `depth`, i.e. the loop counter of the depth-th nested loop of
+    // the equivalent recursive backtracking algorithm.
+    c: [usize; MAXKPERM],
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T> KPermutations<'a, T> {
+    /// Create a new `KPermutations`, enumerating arrangements of length
+    /// `k` drawn from `data`.
+    ///
+    /// **Panics** if `k > data.len()` or `k > MAXKPERM`.
+    pub fn new(data: &'a mut [T], k: usize) -> Self {
+        assert!(k <= data.len(), "KPermutations: k must not exceed the number of elements");
+        assert!(k <= MAXKPERM, "KPermutations: only up to {} elements are supported", MAXKPERM);
+        KPermutations {
+            data: data,
+            k: k,
+            c: [0; MAXKPERM],
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Step into the next *k*-permutation and return a reference to
+    /// `data` with it in its first `k` elements. Return `None` when all
+    /// `P(n, k)` arrangements have been visited.
+    pub fn next_permutation(&mut self) -> Option<&mut [T]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            for d in 0..self.k {
+                self.c[d] = d;
+            }
+            return Some(&mut self.data[..self.k]);
+        }
+        let n = self.data.len();
+        let mut d = self.k;
+        loop {
+            if d == 0 {
+                self.done = true;
+                return None;
+            }
+            d -= 1;
+            // Undo the swap this depth made, then try the next candidate.
+            self.data.swap(d, self.c[d]);
+            self.c[d] += 1;
+            if self.c[d] < n {
+                self.data.swap(d, self.c[d]);
+                // Deeper depths restart from their identity candidate.
+                for e in d + 1..self.k {
+                    self.c[e] = e;
+                }
+                return Some(&mut self.data[..self.k]);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Iterate the *k*-permutations.
+impl<'a, T: Clone> Iterator for KPermutations<'a, T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Vec<T>> {
+        self.next_permutation().map(|perm| perm.to_vec())
+    }
+}
+
+/// Call `f` once for each of the `P(n, k) = n! / (n - k)!` partial
+/// permutations of length `k` drawn from `xs`, in the style of
+/// `heap_recursive`.
+///
+/// `f` receives the current arrangement as the first `k` elements of
+/// `xs`; elements from `k` onward are left in an unspecified order
+/// between calls. `f`'s return value must implement `ControlFlow`;
+/// returning a breaking value stops the enumeration early and
+/// `k_permutations_recursive` returns that same value.
+///
+/// **Panics** if `k > xs.len()`.
+///
+/// ## Example
+///
+/// ```
+/// use permutohedron::k_permutations_recursive;
+///
+/// let mut data = [1, 2, 3, 4];
+/// let mut permutations = Vec::new();
+/// k_permutations_recursive(&mut data, 2, |p| {
+///     permutations.push(p.to_vec())
+/// });
+///
+/// assert_eq!(permutations.len(), 12); // P(4, 2) = 4 * 3
+/// ```
+pub fn k_permutations_recursive<T, F, R>(xs: &mut [T], k: usize, mut f: F) -> R
+    where F: FnMut(&mut [T]) -> R,
+          R: ::ControlFlow,
+{
+    assert!(k <= xs.len(), "k_permutations_recursive: k must not exceed the number of elements");
+    k_permutations_recursive_(xs, k, 0, &mut f)
+}
+
+fn k_permutations_recursive_<T, F, R>(xs: &mut [T], k: usize, depth: usize, f: &mut F) -> R
+    where F: FnMut(&mut [T]) -> R,
+          R: ::ControlFlow,
+{
+    if depth == k {
+        return f(&mut xs[..k]);
+    }
+    for i in depth..xs.len() {
+        xs.swap(depth, i);
+        try_control!(k_permutations_recursive_(xs, k, depth + 1, f));
+        xs.swap(depth, i);
+    }
+    R::continuing()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn factorial(n: usize) -> usize {
+        (1..n + 1).product()
+    }
+
+    fn perm_count(n: usize, k: usize) -> usize {
+        factorial(n) / factorial(n - k)
+    }
+
+    #[test]
+    fn k_permutations_struct_visits_each_once() {
+        for n in 0..6 {
+            for k in 0..n + 1 {
+                let mut data = (0..n).collect::<Vec<_>>();
+                let mut seen = HashSet::new();
+                let mut kp = KPermutations::new(&mut data, k);
+                while let Some(p) = kp.next_permutation() {
+                    assert!(seen.insert(p.to_vec()));
+                }
+                assert_eq!(seen.len(), perm_count(n, k));
+            }
+        }
+    }
+
+    #[test]
+    fn k_permutations_recursive_visits_each_once() {
+        for n in 0..6 {
+            for k in 0..n + 1 {
+                let mut data = (0..n).collect::<Vec<_>>();
+                let mut seen = HashSet::new();
+                k_permutations_recursive(&mut data, k, |p| {
+                    assert!(seen.insert(p.to_vec()));
+                });
+                assert_eq!(seen.len(), perm_count(n, k));
+            }
+        }
+    }
+}
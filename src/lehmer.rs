@@ -0,0 +1,175 @@
+//! Permutation ranking and unranking using the factorial number system
+//! (the Lehmer code).
+//!
+//! This gives *O(n²)* random access into the lexicographic ordering of
+//! permutations of `0..n`, complementing the sequential generators
+//! (`Heap`, `heap_recursive`, `LexicalPermutation`) with direct access to
+//! the *k*-th permutation, and the inverse operation of finding the rank
+//! of a given arrangement.
+
+use super::factorial;
+
+/// Maximum number of elements supported by `rank` and `unrank`.
+///
+/// `21!` does not fit in a `usize`, so ranks and permutations are limited
+/// to this many elements.
+pub const MAXRANK: usize = 20;
+
+/// Compute the lexicographic rank of a permutation: its index in the
+/// ordered sequence of all permutations of its elements.
+///
+/// The rank is computed from the permutation's Lehmer code: for each
+/// position `i` (from the left), count the number of elements to the
+/// right of `i` that are smaller than `perm[i]`; multiply that count by
+/// `(n - 1 - i)!`, and sum over all positions.
+///
+/// **Panics** if `perm.len()` is greater than `MAXRANK`.
+///
+/// ## Example
+///
+/// ```
+/// use permutohedron::rank;
+///
+/// assert_eq!(rank(&[0, 1, 2]), 0);
+/// assert_eq!(rank(&[2, 1, 0]), 5);
+/// ```
+pub fn rank<T: Ord>(perm: &[T]) -> usize {
+    let n = perm.len();
+    assert!(n <= MAXRANK, "rank: only permutations of up to {} elements are supported", MAXRANK);
+    let mut index = 0;
+    for i in 0..n {
+        let smaller = perm[i + 1..].iter().filter(|x| **x < perm[i]).count();
+        index += smaller * factorial(n - 1 - i);
+    }
+    index
+}
+
+/// Produce the permutation of `0..n` with the given lexicographic `index`,
+/// written into `out`.
+///
+/// Returns `None` if `index >= n!` (there is no permutation with that
+/// rank), in which case `out` is left unmodified; returns `Some(())` and
+/// fills `out` otherwise.
+///
+/// **Panics** if `n` is greater than `MAXRANK`, or if `out.len() != n`.
+///
+/// ## Example
+///
+/// ```
+/// use permutohedron::unrank;
+///
+/// let mut out = [0; 3];
+/// unrank(3, 5, &mut out).unwrap();
+/// assert_eq!(out, [2, 1, 0]);
+///
+/// assert_eq!(unrank(3, 6, &mut out), None);
+/// ```
+pub fn unrank(n: usize, mut index: usize, out: &mut [usize]) -> Option<()> {
+    assert!(n <= MAXRANK, "unrank: only permutations of up to {} elements are supported", MAXRANK);
+    assert_eq!(out.len(), n, "unrank: `out` must have length `n`");
+    if index >= factorial(n) {
+        return None;
+    }
+
+    // Decompose `index` into its Lehmer digits by repeated division in the
+    // factorial number system, least significant first, then reverse to
+    // get d_0, d_1, ..., d_{n-1} (most significant, i.e. leftmost, first).
+    let mut digits = [0usize; MAXRANK];
+    for k in 1..n {
+        digits[k] = index % (k + 1);
+        index /= k + 1;
+    }
+    digits[..n].reverse();
+
+    // Greedily pick the d_i-th still-available element from the 0..n pool.
+    let mut used = [false; MAXRANK];
+    for i in 0..n {
+        let mut remaining = digits[i];
+        let mut chosen = 0;
+        for candidate in 0..n {
+            if !used[candidate] {
+                if remaining == 0 {
+                    chosen = candidate;
+                    break;
+                }
+                remaining -= 1;
+            }
+        }
+        used[chosen] = true;
+        out[i] = chosen;
+    }
+    Some(())
+}
+
+/// An iterator over permutations of `0..n` in lexicographic order,
+/// starting from an arbitrary rank.
+///
+/// Each permutation is computed directly from its rank via `unrank`,
+/// independent of the previous one.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct Lehmer {
+    n: usize,
+    index: usize,
+}
+
+#[cfg(feature = "std")]
+impl Lehmer {
+    /// Create an iterator over permutations of `0..n`, starting at the
+    /// permutation with lexicographic rank `start`.
+    pub fn new(n: usize, start: usize) -> Self {
+        Lehmer { n: n, index: start }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Iterator for Lehmer {
+    type Item = Vec<usize>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut out = vec![0; self.n];
+        let index = self.index;
+        self.index += 1;
+        unrank(self.n, index, &mut out).map(|_| out)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_and_unrank_roundtrip() {
+        let mut data = [0, 1, 2, 3, 4];
+        let mut out = [0; 5];
+        let mut count = 0;
+        loop {
+            let r = rank(&data);
+            unrank(5, r, &mut out).unwrap();
+            assert_eq!(&out[..], &data[..]);
+            count += 1;
+            if !::LexicalPermutation::next_permutation(&mut data[..]) {
+                break;
+            }
+        }
+        assert_eq!(count, factorial(5));
+    }
+
+    #[test]
+    fn unrank_out_of_range() {
+        let mut out = [0; 4];
+        assert_eq!(unrank(4, factorial(4), &mut out), None);
+        assert!(unrank(4, factorial(4) - 1, &mut out).is_some());
+    }
+
+    #[test]
+    fn lehmer_iterator_matches_unrank() {
+        let perms = Lehmer::new(4, 2).take(3).collect::<Vec<_>>();
+        let mut expected = Vec::new();
+        for index in 2..5 {
+            let mut out = vec![0; 4];
+            unrank(4, index, &mut out).unwrap();
+            expected.push(out);
+        }
+        assert_eq!(perms, expected);
+    }
+}
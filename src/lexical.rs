@@ -0,0 +1,118 @@
+//! Lexicographic permutation of a slice in place.
+
+/// Extension trait for slices to permute themselves in place,
+/// lexicographically.
+///
+/// Adapted from the permutation logic that used to be exposed by the
+/// Rust standard library.
+pub trait LexicalPermutation {
+    /// Rearrange self into the next lexicographic permutation, in place.
+    ///
+    /// Returns `true` if successful; if this is already the last ordered
+    /// permutation, then this method returns `false` and rearranges
+    /// self to the first permutation.
+    fn next_permutation(&mut self) -> bool;
+
+    /// Rearrange self into the previous lexicographic permutation, in place.
+    ///
+    /// Returns `true` if successful; if this is already the first ordered
+    /// permutation, then this method returns `false` and rearranges
+    /// self to the last permutation.
+    fn prev_permutation(&mut self) -> bool;
+}
+
+impl<T> LexicalPermutation for [T] where T: PartialOrd
+{
+    fn next_permutation(&mut self) -> bool {
+        // These cases only have 1 permutation each, so we can't do anything.
+        if self.len() < 2 { return false; }
+
+        // Step 1: Identify the longest, rightmost weakly decreasing part of the vector
+        let mut i = self.len() - 1;
+        while i > 0 && self[i - 1] >= self[i] {
+            i -= 1;
+        }
+
+        // If that is the entire vector, this is the last-ordered permutation.
+        if i == 0 {
+            self.reverse();
+            return false;
+        }
+
+        // Step 2: Find the rightmost element larger than the pivot (i - 1)
+        let mut j = self.len() - 1;
+        while j >= i && self[j] <= self[i - 1] {
+            j -= 1;
+        }
+
+        // Step 3: Swap that element with the pivot
+        self.swap(j, i - 1);
+
+        // Step 4: Reverse the (previously) weakly decreasing part
+        self[i..].reverse();
+
+        true
+    }
+
+    fn prev_permutation(&mut self) -> bool {
+        // These cases only have 1 permutation each, so we can't do anything.
+        if self.len() < 2 { return false; }
+
+        // Step 1: Identify the longest, rightmost weakly increasing part of the vector
+        let mut i = self.len() - 1;
+        while i > 0 && self[i - 1] <= self[i] {
+            i -= 1;
+        }
+
+        // If that is the entire vector, this is the first-ordered permutation.
+        if i == 0 {
+            self.reverse();
+            return false;
+        }
+
+        // Step 2: Find the rightmost element smaller than the pivot (i - 1)
+        let mut j = self.len() - 1;
+        while j >= i && self[j] >= self[i - 1] {
+            j -= 1;
+        }
+
+        // Step 3: Swap that element with the pivot
+        self.swap(j, i - 1);
+
+        // Step 4: Reverse the (previously) weakly increasing part
+        self[i..].reverse();
+
+        true
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_permutation_exhausts_in_order() {
+        let mut data = [0, 1, 2];
+        let mut seen = Vec::new();
+        loop {
+            seen.push(data);
+            if !data.next_permutation() {
+                break;
+            }
+        }
+        assert_eq!(seen, vec![
+            [0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0],
+        ]);
+        // wrapped back around to the first permutation
+        assert_eq!(data, [0, 1, 2]);
+    }
+
+    #[test]
+    fn next_and_prev_are_inverses() {
+        let mut data = [2, 1, 3, 0];
+        let original = data;
+        assert!(data.next_permutation());
+        assert!(data.prev_permutation());
+        assert_eq!(data, original);
+    }
+}
@@ -13,8 +13,23 @@ extern crate core as std;
 use std::marker::PhantomData;
 
 pub use lexical::LexicalPermutation;
+pub use lehmer::{rank, unrank, MAXRANK};
+#[cfg(feature = "std")]
+pub use lehmer::Lehmer;
+pub use control::{Control, ControlFlow};
+pub use sjt::{ElementSwaps, JohnsonTrotter, MAXSJT};
+pub use kperm::{KPermutations, k_permutations_recursive, MAXKPERM};
+#[cfg(feature = "std")]
+pub use unique::unique_permutations;
 
+#[macro_use]
+mod control;
 mod lexical;
+mod lehmer;
+mod sjt;
+mod kperm;
+#[cfg(feature = "std")]
+mod unique;
 
 /// Heap's algorithm for generating permutations, recursive version.
 ///
@@ -22,6 +37,12 @@ mod lexical;
 /// only a small number of elements is practical), and is generally
 /// a bit faster than the iterative version.
 ///
+/// `f` is called once for each permutation. Its return value must
+/// implement `ControlFlow`; returning `Control::Break(b)` stops the
+/// enumeration early and `heap_recursive` returns that same value.
+/// Plain `FnMut(&mut [T])` closures (returning `()`) keep working as
+/// before and always run to completion.
+///
 /// ## Example
 ///
 /// ```
@@ -35,15 +56,30 @@ mod lexical;
 ///
 /// assert_eq!(permutations.len(), 720);
 /// ```
-pub fn heap_recursive<T, F>(xs: &mut [T], mut f: F) where F: FnMut(&mut [T])
+///
+/// ## Example: early exit
+///
+/// ```
+/// use permutohedron::{heap_recursive, Control};
+///
+/// let mut data = [1, 2, 3, 4];
+/// let found = heap_recursive(&mut data, |p| {
+///     if p[0] == 3 { Control::Break(p.to_vec()) } else { Control::Continue }
+/// });
+///
+/// assert_eq!(found.break_value(), Some(vec![3, 1, 2, 4]));
+/// ```
+pub fn heap_recursive<T, F, R>(xs: &mut [T], mut f: F) -> R
+    where F: FnMut(&mut [T]) -> R,
+          R: ControlFlow,
 {
     match xs.len() {
         0 | 1 => f(xs),
         2 => {
             // [1, 2], [2, 1]
-            f(xs);
+            try_control!(f(xs));
             xs.swap(0, 1);
-            f(xs);
+            f(xs)
         }
         n => heap_unrolled_(n, xs, &mut f),
     }
@@ -53,33 +89,34 @@ pub fn heap_recursive<T, F>(xs: &mut [T], mut f: F) where F: FnMut(&mut [T])
 // i.e. don't swap the same items (for example index 0) every time.
 
 /// Unrolled version of heap's algorithm due to Sedgewick
-fn heap_unrolled_<T, F>(n: usize, xs: &mut [T], f: &mut F)
-    where F: FnMut(&mut [T])
+fn heap_unrolled_<T, F, R>(n: usize, xs: &mut [T], f: &mut F) -> R
+    where F: FnMut(&mut [T]) -> R,
+          R: ControlFlow,
 {
     debug_assert!(n >= 3);
     match n {
         3 => {
             // [1, 2, 3], [2, 1, 3], [3, 1, 2], [1, 3, 2], [2, 3, 1], [3, 2, 1]
-            f(xs);
+            try_control!(f(xs));
             xs.swap(0, 1);
-            f(xs);
+            try_control!(f(xs));
             xs.swap(0, 2);
-            f(xs);
+            try_control!(f(xs));
             xs.swap(0, 1);
-            f(xs);
+            try_control!(f(xs));
             xs.swap(0, 2);
-            f(xs);
+            try_control!(f(xs));
             xs.swap(0, 1);
-            f(xs);
+            f(xs)
         }
         n => {
             for i in 0..n - 1 {
-                heap_unrolled_(n - 1, xs, f);
+                try_control!(heap_unrolled_(n - 1, xs, f));
                 let j = if n % 2 == 0 { i } else { 0 };
                 // One swap *between* each iteration.
                 xs.swap(j, n - 1);
             }
-            heap_unrolled_(n - 1, xs, f);
+            heap_unrolled_(n - 1, xs, f)
         }
     }
 }
@@ -184,6 +221,25 @@ impl<'a, T, Data: ?Sized> Heap<'a, Data, T>
             None
         }
     }
+
+    /// Drive the permutations walker, calling `f` once for each
+    /// permutation, short-circuiting if `f` returns a `ControlFlow` value
+    /// that breaks (see `heap_recursive` for the same facility on the
+    /// recursive algorithm).
+    ///
+    /// Returns `R::continuing()` if `f` never broke and all permutations
+    /// were visited, otherwise the breaking `R` value.
+    pub fn control_next<F, R>(&mut self, mut f: F) -> R
+        where F: FnMut(&mut Data) -> R,
+              R: ControlFlow,
+    {
+        loop {
+            match self.next_permutation() {
+                None => return R::continuing(),
+                Some(perm) => try_control!(f(perm)),
+            }
+        }
+    }
 }
 
 #[cfg(feature = "std")]
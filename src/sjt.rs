@@ -0,0 +1,221 @@
+//! The Steinhaus–Johnson–Trotter algorithm: generate permutations where
+//! each consecutive pair differs by a single adjacent transposition.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Direction {
+    Pos,
+    Neg,
+}
+
+/// Maximum number of elements supported by `ElementSwaps` (and
+/// `JohnsonTrotter`), so that its per-element direction and value
+/// bookkeeping can live in fixed-size arrays instead of allocating.
+pub const MAXSJT: usize = 32;
+
+/// An iterator over the adjacent-transposition swaps that, applied in
+/// sequence to a slice of `n` elements starting from its identity
+/// arrangement, visit every permutation of `0..n` exactly once.
+///
+/// Each item `(i, i + 1)` is the pair of indices to swap to move from one
+/// permutation to the next in the Steinhaus–Johnson–Trotter ordering, so
+/// unlike `Heap`, consecutive permutations always differ by a single
+/// adjacent transposition. This lets a caller keep a parallel array (or
+/// an incrementally updated score) in sync by applying the same swap,
+/// instead of recomputing it from scratch at each step.
+///
+/// Algorithm: each of the `n` elements carries a direction (left or
+/// right). An element is *mobile* if it is strictly greater than the
+/// neighbor its direction points to. At each step, the largest mobile
+/// element is swapped with that neighbor, and every element larger than
+/// it has its direction flipped; the iterator is exhausted once no
+/// element is mobile.
+#[derive(Clone, Debug)]
+pub struct ElementSwaps {
+    n: usize,
+    values: [usize; MAXSJT],
+    dirs: [Direction; MAXSJT],
+    done: bool,
+}
+
+impl ElementSwaps {
+    /// Create an `ElementSwaps` iterator for `n` elements.
+    ///
+    /// **Panics** if `n` is greater than `MAXSJT`.
+    pub fn new(n: usize) -> Self {
+        assert!(n <= MAXSJT, "ElementSwaps: only up to {} elements are supported", MAXSJT);
+        let mut values = [0; MAXSJT];
+        for (i, v) in values[..n].iter_mut().enumerate() {
+            *v = i + 1;
+        }
+        ElementSwaps {
+            n: n,
+            values: values,
+            dirs: [Direction::Neg; MAXSJT],
+            done: n < 2,
+        }
+    }
+
+    fn neighbor(&self, p: usize) -> Option<usize> {
+        match self.dirs[p] {
+            Direction::Neg => if p == 0 { None } else { Some(p - 1) },
+            Direction::Pos => if p + 1 < self.n { Some(p + 1) } else { None },
+        }
+    }
+
+    fn mobile_element(&self) -> Option<usize> {
+        let mut found = None;
+        for p in 0..self.n {
+            if let Some(np) = self.neighbor(p) {
+                if self.values[np] < self.values[p] {
+                    if found.map_or(true, |f| self.values[p] > self.values[f]) {
+                        found = Some(p);
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+impl Iterator for ElementSwaps {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.done {
+            return None;
+        }
+        let p = match self.mobile_element() {
+            None => {
+                self.done = true;
+                return None;
+            }
+            Some(p) => p,
+        };
+        let np = self.neighbor(p).expect("mobile element always has a neighbor");
+        let moved_value = self.values[p];
+        self.values.swap(p, np);
+        self.dirs.swap(p, np);
+        for i in 0..self.n {
+            if self.values[i] > moved_value {
+                self.dirs[i] = match self.dirs[i] {
+                    Direction::Pos => Direction::Neg,
+                    Direction::Neg => Direction::Pos,
+                };
+            }
+        }
+        Some((p.min(np), p.max(np)))
+    }
+}
+
+/// Permute a slice in place through every arrangement of its elements,
+/// using the Steinhaus–Johnson–Trotter algorithm.
+///
+/// This is a convenience wrapper around `ElementSwaps` for callers who
+/// just want the successive permutations of `data` itself, rather than
+/// the raw swap indices.
+///
+/// **Panics** if `data.len()` is greater than `MAXSJT`.
+///
+/// ## Example
+///
+/// ```
+/// use permutohedron::JohnsonTrotter;
+///
+/// let mut data = [1, 2, 3];
+/// let mut permutations = Vec::new();
+/// let mut jt = JohnsonTrotter::new(&mut data);
+/// while let Some(perm) = jt.next_permutation() {
+///     permutations.push(perm.to_vec());
+/// }
+///
+/// assert_eq!(permutations.len(), 6);
+/// ```
+pub struct JohnsonTrotter<'a, T: 'a> {
+    data: &'a mut [T],
+    swaps: ElementSwaps,
+    started: bool,
+}
+
+impl<'a, T> JohnsonTrotter<'a, T> {
+    /// Create a new `JohnsonTrotter` over `data`.
+    ///
+    /// **Panics** if `data.len()` is greater than `MAXSJT`.
+    pub fn new(data: &'a mut [T]) -> Self {
+        let n = data.len();
+        JohnsonTrotter {
+            data: data,
+            swaps: ElementSwaps::new(n),
+            started: false,
+        }
+    }
+
+    /// Step `data` into the next permutation and return a reference to
+    /// it. Return `None` when all permutations have been visited.
+    pub fn next_permutation(&mut self) -> Option<&mut [T]> {
+        if !self.started {
+            self.started = true;
+            return Some(self.data);
+        }
+        match self.swaps.next() {
+            None => None,
+            Some((i, j)) => {
+                self.data.swap(i, j);
+                Some(self.data)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Iterate the permutations.
+impl<'a, T: Clone> Iterator for JohnsonTrotter<'a, T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Vec<T>> {
+        self.next_permutation().map(|data| data.to_vec())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn factorial(n: usize) -> usize {
+        (1..n + 1).product()
+    }
+
+    #[test]
+    fn element_swaps_visits_each_permutation_once() {
+        for n in 0..7 {
+            let mut data = (0..n).collect::<Vec<_>>();
+            let mut seen = HashSet::new();
+            seen.insert(data.clone());
+            for (i, j) in ElementSwaps::new(n) {
+                data.swap(i, j);
+                seen.insert(data.clone());
+            }
+            assert_eq!(seen.len(), factorial(n));
+        }
+    }
+
+    #[test]
+    fn element_swaps_are_adjacent() {
+        for (i, j) in ElementSwaps::new(5) {
+            assert_eq!(j, i + 1);
+        }
+    }
+
+    #[test]
+    fn johnson_trotter_matches_element_swaps() {
+        let mut data = [0, 1, 2, 3];
+        let perms = JohnsonTrotter::new(&mut data).collect::<Vec<_>>();
+        assert_eq!(perms.len(), factorial(4));
+        let mut expected = vec![0, 1, 2, 3];
+        let mut expected_perms = vec![expected.clone()];
+        for (i, j) in ElementSwaps::new(4) {
+            expected.swap(i, j);
+            expected_perms.push(expected.clone());
+        }
+        assert_eq!(perms, expected_perms);
+    }
+}
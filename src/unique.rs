@@ -0,0 +1,91 @@
+//! Enumerate only the *distinct* permutations of a multiset, skipping the
+//! repeated arrangements that `Heap`/`heap_recursive` would otherwise
+//! emit when the input contains equal elements.
+
+use LexicalPermutation;
+
+/// Call `f` once for each distinct permutation of `data`, skipping
+/// duplicates when `data` contains equal elements.
+///
+/// Requires `T: Ord`. `data` is sorted first (the starting point
+/// `LexicalPermutation::next_permutation` requires to enumerate every
+/// ordering), then stepped with `next_permutation` until it returns
+/// `false`, calling `f` after every step including the initial sorted
+/// arrangement. The number of calls to `f` is the multinomial
+/// coefficient `n! / (m_1! * m_2! * ...)` of the multiset, rather than
+/// the full `n!`.
+///
+/// `f`'s return value must implement `ControlFlow`; returning a breaking
+/// value stops the enumeration early and `unique_permutations` returns
+/// that same value.
+///
+/// ## Example
+///
+/// ```
+/// use permutohedron::unique_permutations;
+///
+/// let mut data = [1, 1, 2];
+/// let mut permutations = Vec::new();
+/// unique_permutations(&mut data, |p| permutations.push(p.to_vec()));
+///
+/// assert_eq!(permutations, vec![
+///     vec![1, 1, 2],
+///     vec![1, 2, 1],
+///     vec![2, 1, 1],
+/// ]);
+/// ```
+pub fn unique_permutations<T, F, R>(data: &mut [T], mut f: F) -> R
+    where T: Ord,
+          F: FnMut(&mut [T]) -> R,
+          R: ::ControlFlow,
+{
+    data.sort();
+    try_control!(f(data));
+    while data.next_permutation() {
+        try_control!(f(data));
+    }
+    R::continuing()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn factorial(n: usize) -> usize {
+        (1..n + 1).product()
+    }
+
+    #[test]
+    fn skips_duplicates() {
+        let mut data = [1, 1, 2, 2];
+        let mut permutations = Vec::new();
+        unique_permutations(&mut data, |p| permutations.push(p.to_vec()));
+        // 4! / (2! * 2!) = 6 distinct arrangements
+        assert_eq!(permutations.len(), 6);
+        let mut sorted = permutations.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), permutations.len());
+    }
+
+    #[test]
+    fn matches_full_factorial_when_all_distinct() {
+        let mut data = [3, 1, 2];
+        let mut count = 0;
+        unique_permutations(&mut data, |_| count += 1);
+        assert_eq!(count, factorial(3));
+    }
+
+    #[test]
+    fn control_flow_breaks_early() {
+        use ::Control;
+        let mut data = [1, 1, 2];
+        let mut seen = 0;
+        let result = unique_permutations(&mut data, |p| {
+            seen += 1;
+            if p == [1, 2, 1] { Control::Break(p.to_vec()) } else { Control::Continue }
+        });
+        assert_eq!(result.break_value(), Some(vec![1, 2, 1]));
+        assert_eq!(seen, 2);
+    }
+}